@@ -1,15 +1,73 @@
+use std::env;
 use std::fs;
 use std::io;
+use std::os::unix::fs::DirBuilderExt;
+use std::path::Path;
+use std::process;
 
 fn main() {
-    let mut dirname = String::new();
+    let args: Vec<String> = env::args().skip(1).collect();
 
-    io::stdin().read_line(&mut dirname)
-        .expect("Failed to read line");
+    let mut parents = false;
+    let mut verbose = false;
+    let mut mode: Option<u32> = None;
+    let mut dirs = Vec::new();
 
-    do_mkdir(dirname);
+    let mut iter = args.into_iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "-p" | "--parents" => parents = true,
+            "-v" | "--verbose" => verbose = true,
+            "-m" | "--mode" => {
+                let raw = iter.next().unwrap_or_else(|| {
+                    eprintln!("mkdir: option '{}' requires an argument", arg);
+                    process::exit(1);
+                });
+                match parse_mode(&raw) {
+                    Ok(m) => mode = Some(m),
+                    Err(e) => {
+                        eprintln!("mkdir: invalid mode '{}': {}", raw, e);
+                        process::exit(1);
+                    }
+                }
+            }
+            _ => dirs.push(arg),
+        }
+    }
+
+    let mut exit_code = 0;
+
+    for dirname in dirs {
+        if let Err(e) = do_mkdir(&dirname, parents, verbose, mode) {
+            eprintln!("mkdir: cannot create directory '{}': {}", dirname, e.kind());
+            exit_code = 1;
+        }
+    }
+
+    process::exit(exit_code);
+}
+
+fn parse_mode(raw: &str) -> Result<u32, String> {
+    if raw.len() != 3 && raw.len() != 4 {
+        return Err(format!("'{}' is not a 3- or 4-digit octal mode", raw));
+    }
+
+    u32::from_str_radix(raw, 8).map_err(|_| format!("'{}' is not a valid octal number", raw))
 }
 
-fn do_mkdir(dirname: String){
-    fs::create_dir(dirname);
-}
\ No newline at end of file
+fn do_mkdir(dirname: &str, parents: bool, verbose: bool, mode: Option<u32>) -> io::Result<()> {
+    let already_existed = parents && Path::new(dirname).is_dir();
+
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(parents);
+    if let Some(m) = mode {
+        builder.mode(m);
+    }
+    builder.create(dirname)?;
+
+    if verbose && !already_existed {
+        println!("mkdir: created directory '{}'", dirname);
+    }
+
+    Ok(())
+}